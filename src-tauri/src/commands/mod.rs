@@ -1,7 +1,11 @@
 //! IPC commands exposed to the frontend layer.
 
-use crate::core::logging::log_message;
-use crate::core::python::{execute_python_conversion, BackendResult, ConvertPayload};
+use crate::core::logging::{get_log_path as resolve_log_path, log_message};
+use crate::core::python::{
+    cancel_job, execute_python_conversion_pooled, execute_python_pipeline, BackendResult,
+    ConvertPayload,
+};
+use crate::core::script::build_pipeline;
 
 #[tauri::command]
 pub fn ping() -> String {
@@ -20,9 +24,11 @@ pub async fn convert_audio(
 
     let app_handle = app.clone();
 
-    tauri::async_runtime::spawn_blocking(move || execute_python_conversion(app_handle, payload))
-        .await
-        .map_err(|err| format!("Background task join error: {}", err))?
+    tauri::async_runtime::spawn_blocking(move || {
+        execute_python_conversion_pooled(app_handle, payload)
+    })
+    .await
+    .map_err(|err| format!("Background task join error: {}", err))?
 }
 
 #[tauri::command]
@@ -37,8 +43,53 @@ pub async fn analyze_audio(
 
     let app_handle = app.clone();
 
-    // Reuse execute_python_conversion as it handles the JSON IPC
-    tauri::async_runtime::spawn_blocking(move || execute_python_conversion(app_handle, payload))
+    // Reuse execute_python_conversion_pooled as it handles the JSON IPC
+    tauri::async_runtime::spawn_blocking(move || {
+        execute_python_conversion_pooled(app_handle, payload)
+    })
+    .await
+    .map_err(|err| format!("Background task join error: {}", err))?
+}
+
+/// Evaluates a user-supplied Lua script into an ordered pipeline of
+/// conversion steps and runs it against the Python backend.
+#[tauri::command]
+pub async fn run_pipeline(
+    app: tauri::AppHandle,
+    script: String,
+    payload: ConvertPayload,
+) -> Result<BackendResult, String> {
+    log_message(
+        "tauri",
+        &format!("Received run_pipeline with {} files", payload.files.len()),
+    );
+
+    let steps = tauri::async_runtime::spawn_blocking(move || build_pipeline(&script))
+        .await
+        .map_err(|err| format!("Background task join error: {}", err))??;
+
+    tauri::async_runtime::spawn_blocking(move || execute_python_pipeline(app, payload, steps))
         .await
         .map_err(|err| format!("Background task join error: {}", err))?
 }
+
+/// Cancels an in-flight conversion or analysis job started by [`convert_audio`]
+/// or [`analyze_audio`]. The frontend learns the job id from the
+/// `conversion-started` event emitted when the job begins.
+#[tauri::command]
+pub fn cancel_conversion(job_id: String) -> Result<(), String> {
+    if cancel_job(&job_id) {
+        log_message("tauri", &format!("Cancel requested for job {}", job_id));
+        Ok(())
+    } else {
+        Err(format!("No running job with id {}", job_id))
+    }
+}
+
+/// Returns the active rotating log file path so the UI can offer "open logs".
+#[tauri::command]
+pub fn get_log_path() -> Result<String, String> {
+    resolve_log_path()
+        .map(|path| path.to_string_lossy().to_string())
+        .ok_or_else(|| "Logging has not been initialized yet".to_string())
+}
@@ -1,7 +1,214 @@
-//! Structured logging helpers for the Tauri layer.
+//! Structured logging for the Tauri layer.
+//!
+//! Every call mirrors a line to the console and, once [`init_logging`] has
+//! pointed the module at the app's data directory, appends it to a rotating
+//! log file so a failed conversion's Python traceback survives past the
+//! lifetime of the console.
 
 use chrono::Utc;
+use once_cell::sync::Lazy;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tauri::Manager;
 
+/// Severity of a structured log entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+/// How many rotated log files to keep under the log directory.
+const MAX_LOG_FILES: usize = 5;
+const LOG_FILE_STEM: &str = "hamonix";
+
+static LOG_DIR: Lazy<RwLock<Option<PathBuf>>> = Lazy::new(|| RwLock::new(None));
+
+/// Points future [`log_message`]/[`log_event`] calls at a rotating log file
+/// under the app's data directory, rotating out old files first. Safe to
+/// call once at startup; logging falls back to console-only if it fails.
+pub fn init_logging(app: &tauri::AppHandle) {
+    let Some(data_dir) = app.path_resolver().app_data_dir() else {
+        log_message(
+            "logging",
+            "Unable to resolve app data dir; file logging disabled",
+        );
+        return;
+    };
+
+    let log_dir = data_dir.join("logs");
+    if let Err(err) = fs::create_dir_all(&log_dir) {
+        log_message(
+            "logging",
+            &format!(
+                "Failed to create log directory {}: {}",
+                log_dir.display(),
+                err
+            ),
+        );
+        return;
+    }
+
+    if let Err(err) = rotate_logs(&log_dir) {
+        log_message("logging", &format!("Failed to rotate log files: {}", err));
+    }
+
+    let log_path = current_log_path(&log_dir);
+    *LOG_DIR.write().unwrap() = Some(log_dir);
+    log_message("logging", &format!("Logging to {}", log_path.display()));
+}
+
+/// Returns the active log file path, if [`init_logging`] has run successfully.
+pub fn get_log_path() -> Option<PathBuf> {
+    LOG_DIR
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|log_dir| current_log_path(log_dir))
+}
+
+fn current_log_path(log_dir: &Path) -> PathBuf {
+    log_dir.join(format!("{}.log", LOG_FILE_STEM))
+}
+
+/// Rotates `hamonix.log` -> `hamonix.1.log` -> ... , dropping whatever is
+/// already at the oldest slot so at most `MAX_LOG_FILES` files remain.
+fn rotate_logs(log_dir: &Path) -> std::io::Result<()> {
+    let oldest = log_dir.join(format!("{}.{}.log", LOG_FILE_STEM, MAX_LOG_FILES - 1));
+    if oldest.exists() {
+        fs::remove_file(&oldest)?;
+    }
+
+    for index in (1..MAX_LOG_FILES - 1).rev() {
+        let from = log_dir.join(format!("{}.{}.log", LOG_FILE_STEM, index));
+        let to = log_dir.join(format!("{}.{}.log", LOG_FILE_STEM, index + 1));
+        if from.exists() {
+            fs::rename(from, to)?;
+        }
+    }
+
+    let current = current_log_path(log_dir);
+    if current.exists() {
+        fs::rename(&current, log_dir.join(format!("{}.1.log", LOG_FILE_STEM)))?;
+    }
+
+    Ok(())
+}
+
+/// Logs a plain informational message. Most call sites want this; reach for
+/// [`log_event`] directly when a level or job id is worth recording.
 pub fn log_message(scope: &str, message: &str) {
-    println!("[{}] [{}] {}", scope, Utc::now().to_rfc3339(), message);
+    log_event(scope, LogLevel::Info, None, message);
+}
+
+/// Logs a structured entry (scope, RFC3339 timestamp, level, job id,
+/// message) to the console and, once initialized, the rotating log file.
+pub fn log_event(scope: &str, level: LogLevel, job_id: Option<&str>, message: &str) {
+    let line = format!(
+        "[{}] [{}] [{}] [{}] {}",
+        scope,
+        Utc::now().to_rfc3339(),
+        level.as_str(),
+        job_id.unwrap_or("-"),
+        message
+    );
+
+    println!("{}", line);
+
+    if let Some(log_dir) = LOG_DIR.read().unwrap().as_ref() {
+        append_to_log_file(log_dir, &line);
+    }
+}
+
+fn append_to_log_file(log_dir: &Path, line: &str) {
+    let path = current_log_path(log_dir);
+    match OpenOptions::new().create(true).append(true).open(&path) {
+        Ok(mut file) => {
+            if let Err(err) = writeln!(file, "{}", line) {
+                eprintln!("[logging] Failed to write log line: {}", err);
+            }
+        }
+        Err(err) => eprintln!(
+            "[logging] Failed to open log file {}: {}",
+            path.display(),
+            err
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_log_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hamonix-logging-test-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn rotated_path(log_dir: &Path, index: usize) -> PathBuf {
+        log_dir.join(format!("{}.{}.log", LOG_FILE_STEM, index))
+    }
+
+    #[test]
+    fn rotate_logs_is_a_noop_with_no_existing_files() {
+        let log_dir = temp_log_dir("empty");
+        rotate_logs(&log_dir).unwrap();
+        assert!(!current_log_path(&log_dir).exists());
+    }
+
+    #[test]
+    fn rotate_logs_shifts_current_and_rotated_files() {
+        let log_dir = temp_log_dir("shift");
+        fs::write(current_log_path(&log_dir), "current").unwrap();
+        fs::write(rotated_path(&log_dir, 1), "rotated-1").unwrap();
+
+        rotate_logs(&log_dir).unwrap();
+
+        assert!(!current_log_path(&log_dir).exists());
+        assert_eq!(
+            fs::read_to_string(rotated_path(&log_dir, 1)).unwrap(),
+            "current"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_path(&log_dir, 2)).unwrap(),
+            "rotated-1"
+        );
+    }
+
+    #[test]
+    fn rotate_logs_drops_the_oldest_file_once_max_is_reached() {
+        let log_dir = temp_log_dir("drop-oldest");
+        fs::write(current_log_path(&log_dir), "current").unwrap();
+        for index in 1..MAX_LOG_FILES {
+            fs::write(rotated_path(&log_dir, index), format!("rotated-{}", index)).unwrap();
+        }
+
+        rotate_logs(&log_dir).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(rotated_path(&log_dir, 1)).unwrap(),
+            "current"
+        );
+        for index in 2..MAX_LOG_FILES {
+            assert_eq!(
+                fs::read_to_string(rotated_path(&log_dir, index)).unwrap(),
+                format!("rotated-{}", index - 1)
+            );
+        }
+    }
 }
@@ -0,0 +1,5 @@
+//! Shared application subsystems used by the Tauri command layer.
+
+pub mod logging;
+pub mod python;
+pub mod script;
@@ -1,13 +1,31 @@
 //! Python backend integration module.
 
-use crate::core::logging::log_message;
+use crate::core::logging::{log_event, log_message, LogLevel};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use tauri::Manager;
 
+/// Minimum gap between two `conversion-progress` events for the same file.
+const PROGRESS_THROTTLE: Duration = Duration::from_millis(100);
+
+/// How often the cancellation watcher checks `cancel_flag`, independent of
+/// whether the child process is still producing stdout lines.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Multiplier on the logical CPU count that gives the hard ceiling on
+/// concurrent shard workers regardless of the caller-supplied `max_parallel`,
+/// so a batch request can't spin up an unbounded number of OS threads and
+/// child Python processes.
+const MAX_WORKER_MULTIPLIER: usize = 4;
+
 #[derive(Debug)]
 struct PythonResolution {
     command: PathBuf,
@@ -17,11 +35,134 @@ struct PythonResolution {
     uses_embedded: bool,
 }
 
+/// Tracks a live Python backend process so it can be cancelled from the frontend.
+struct JobHandle {
+    pid: u32,
+    cancel: Arc<AtomicBool>,
+}
+
+static JOB_ID_SEQ: AtomicU64 = AtomicU64::new(0);
+
+static JOB_REGISTRY: Lazy<RwLock<HashMap<String, JobHandle>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn next_job_id() -> String {
+    format!("job-{}", JOB_ID_SEQ.fetch_add(1, Ordering::Relaxed))
+}
+
+fn register_job(job_id: &str, pid: u32, cancel: Arc<AtomicBool>) {
+    JOB_REGISTRY
+        .write()
+        .unwrap()
+        .insert(job_id.to_string(), JobHandle { pid, cancel });
+}
+
+fn unregister_job(job_id: &str) {
+    JOB_REGISTRY.write().unwrap().remove(job_id);
+}
+
+/// Scope guard that removes a job from the registry when the conversion
+/// finishes or bails out early, including on the `return Err(...)` paths.
+struct JobRegistryGuard<'a> {
+    job_id: &'a str,
+}
+
+impl Drop for JobRegistryGuard<'_> {
+    fn drop(&mut self) {
+        unregister_job(self.job_id);
+    }
+}
+
+/// Maps a batch job id (emitted in the `conversion-started` event by
+/// [`execute_python_conversion_pooled`] when it fans a batch out across
+/// shards) to the individual shard job ids, so a
+/// single `cancel_conversion` call can cancel every shard.
+static BATCH_REGISTRY: Lazy<RwLock<HashMap<String, Vec<String>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn next_batch_id() -> String {
+    format!("batch-{}", JOB_ID_SEQ.fetch_add(1, Ordering::Relaxed))
+}
+
+fn register_batch(batch_id: &str, shard_job_ids: Vec<String>) {
+    BATCH_REGISTRY
+        .write()
+        .unwrap()
+        .insert(batch_id.to_string(), shard_job_ids);
+}
+
+fn unregister_batch(batch_id: &str) {
+    BATCH_REGISTRY.write().unwrap().remove(batch_id);
+}
+
+/// Scope guard that removes a batch from the registry once every shard has
+/// finished, mirroring [`JobRegistryGuard`].
+struct BatchRegistryGuard<'a> {
+    batch_id: &'a str,
+}
+
+impl Drop for BatchRegistryGuard<'_> {
+    fn drop(&mut self) {
+        unregister_batch(self.batch_id);
+    }
+}
+
+/// Stops the cancellation watcher thread and joins it when dropped, including
+/// on early `return Err(...)` paths — otherwise a failure before the normal
+/// "job finished" point would leave the watcher polling forever.
+struct WatcherGuard {
+    finished_flag: Arc<AtomicBool>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for WatcherGuard {
+    fn drop(&mut self) {
+        self.finished_flag.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Flags the job's cooperative-cancel flag and asks the tracked process to
+/// exit. `job_id` may be either a single job id or a batch id emitted by
+/// [`execute_python_conversion_pooled`], in which case every shard in the
+/// batch is cancelled.
+///
+/// Returns `true` if a live job (or at least one shard of a live batch)
+/// matched `job_id`.
+pub fn cancel_job(job_id: &str) -> bool {
+    if let Some(shard_job_ids) = BATCH_REGISTRY.read().unwrap().get(job_id).cloned() {
+        let mut cancelled_any = false;
+        for shard_job_id in shard_job_ids {
+            cancelled_any |= cancel_single_job(&shard_job_id);
+        }
+        return cancelled_any;
+    }
+
+    cancel_single_job(job_id)
+}
+
+fn cancel_single_job(job_id: &str) -> bool {
+    let registry = JOB_REGISTRY.read().unwrap();
+    match registry.get(job_id) {
+        Some(handle) => {
+            handle.cancel.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ConvertPayload {
     pub files: Vec<String>,
     pub format: String,
     pub output: String,
+    /// Maximum number of Python backend processes to run concurrently for
+    /// this batch. Defaults to the number of logical CPUs when unset.
+    #[serde(default)]
+    pub max_parallel: Option<usize>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -32,21 +173,124 @@ pub struct BackendResult {
     pub outputs: Vec<String>,
 }
 
+/// Typed, stable contract for `conversion-progress` events, replacing the raw
+/// JSON passthrough of whatever the Python backend happens to print.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProgressEvent {
+    pub job_id: String,
+    pub file_index: usize,
+    pub total_files: usize,
+    pub current_file: String,
+    pub percent: f32,
+    pub stage: String,
+    /// Percent complete across the whole batch, derived from `file_index` and `percent`.
+    pub overall_percent: f32,
+}
+
+impl ProgressEvent {
+    /// Builds a `ProgressEvent` from one line of raw backend JSON, or `None`
+    /// if it doesn't look like a progress update (e.g. the `complete` event).
+    fn from_backend_value(job_id: &str, total_files: usize, value: &Value) -> Option<Self> {
+        let stage = value.get("event").and_then(|v| v.as_str())?;
+        if stage == "complete" {
+            return None;
+        }
+
+        let file_index = value
+            .get("file_index")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as usize;
+        let current_file = value
+            .get("current_file")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let percent = value
+            .get("percent")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0) as f32;
+
+        Some(ProgressEvent {
+            job_id: job_id.to_string(),
+            file_index,
+            total_files,
+            current_file,
+            percent,
+            stage: stage.to_string(),
+            overall_percent: overall_percent(file_index, total_files, percent),
+        })
+    }
+}
+
+/// Computes the batch-wide percentage given how far through `total_files` we
+/// are and how far through the current file we are.
+fn overall_percent(file_index: usize, total_files: usize, file_percent: f32) -> f32 {
+    if total_files == 0 {
+        return 0.0;
+    }
+
+    let per_file = 100.0 / total_files as f32;
+    (file_index as f32 * per_file) + (file_percent.clamp(0.0, 100.0) / 100.0 * per_file)
+}
+
 /// Execute Python backend with JSON input via stdin and stream progress events.
 pub fn execute_python_conversion(
     app: tauri::AppHandle,
     payload: ConvertPayload,
 ) -> Result<BackendResult, String> {
-    let resolution = resolve_python(&app)?;
+    let total_files = payload.files.len();
+    let json_input = build_convert_request(&payload)?;
+    run_backend_process(app, total_files, json_input, next_job_id(), true)
+}
+
+/// Sends an ordered list of Lua-defined pipeline steps (see
+/// [`crate::core::script::build_pipeline`]) to the Python backend instead of
+/// the fixed `"operation": "convert"` request.
+pub fn execute_python_pipeline(
+    app: tauri::AppHandle,
+    payload: ConvertPayload,
+    steps: Vec<crate::core::script::PipelineStep>,
+) -> Result<BackendResult, String> {
+    let total_files = payload.files.len();
 
     let json_input = serde_json::to_string(&serde_json::json!({
-        "operation": "convert",
+        "operation": "pipeline",
         "files": payload.files,
         "format": payload.format,
         "output": payload.output,
+        "steps": steps,
     }))
     .map_err(|e| format!("Failed to serialize request: {}", e))?;
 
+    run_backend_process(app, total_files, json_input, next_job_id(), true)
+}
+
+/// Builds the stdin JSON payload for a plain `"operation": "convert"` request.
+fn build_convert_request(payload: &ConvertPayload) -> Result<String, String> {
+    serde_json::to_string(&serde_json::json!({
+        "operation": "convert",
+        "files": payload.files,
+        "format": payload.format,
+        "output": payload.output,
+    }))
+    .map_err(|e| format!("Failed to serialize request: {}", e))
+}
+
+/// Spawns the Python backend with `json_input` on stdin under `job_id`,
+/// registers it for cancellation, and streams typed progress events until it
+/// exits. `emit_lifecycle` gates the `conversion-started`/`conversion-cancelled`
+/// events: a pooled batch emits its own single pair of these under the batch
+/// id (see [`execute_python_conversion_pooled`]), so each shard's own events
+/// are suppressed here to avoid N+1 conflicting events for one logical job.
+fn run_backend_process(
+    app: tauri::AppHandle,
+    total_files: usize,
+    json_input: String,
+    job_id: String,
+    emit_lifecycle: bool,
+) -> Result<BackendResult, String> {
+    let resolution = resolve_python(&app)?;
+
     log_message(
         "tauri",
         &format!(
@@ -88,22 +332,82 @@ pub fn execute_python_conversion(
         .env("PYTHONUNBUFFERED", "1")
         .env("PYTHONDONTWRITEBYTECODE", "1");
 
-    let mut child = command
+    let child = command
         .spawn()
         .map_err(|e| format!("Failed to spawn Python process: {}", e))?;
+    let pid = child.id();
+    let child = Arc::new(Mutex::new(child));
+
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    let finished_flag = Arc::new(AtomicBool::new(false));
+    register_job(&job_id, pid, cancel_flag.clone());
+    let _job_guard = JobRegistryGuard { job_id: &job_id };
+
+    if emit_lifecycle {
+        if let Err(err) = app.emit_all(
+            "conversion-started",
+            serde_json::json!({ "job_id": job_id }),
+        ) {
+            log_message("tauri", &format!("Failed to emit start event: {}", err));
+        }
+    }
 
-    if let Some(mut stdin) = child.stdin.take() {
+    // Kills the child as soon as cancellation is requested. This runs
+    // independently of the blocking stdout read below, so a child that has
+    // stalled mid-conversion (and so isn't producing any more lines) still
+    // gets killed promptly instead of only on its next line of output.
+    let watcher_handle = {
+        let watcher_child = child.clone();
+        let watcher_cancel_flag = cancel_flag.clone();
+        let watcher_finished_flag = finished_flag.clone();
+        let watcher_job_id = job_id.clone();
+        std::thread::spawn(move || {
+            while !watcher_finished_flag.load(Ordering::SeqCst) {
+                if watcher_cancel_flag.load(Ordering::SeqCst) {
+                    let pid = JOB_REGISTRY
+                        .read()
+                        .unwrap()
+                        .get(&watcher_job_id)
+                        .map(|handle| handle.pid)
+                        .unwrap_or(0);
+                    log_event(
+                        "tauri",
+                        LogLevel::Warn,
+                        Some(&watcher_job_id),
+                        &format!("Cancelling job (pid {})", pid),
+                    );
+                    if let Ok(mut child) = watcher_child.lock() {
+                        let _ = child.kill();
+                    }
+                    break;
+                }
+                std::thread::sleep(CANCEL_POLL_INTERVAL);
+            }
+        })
+    };
+    let _watcher_guard = WatcherGuard {
+        finished_flag: finished_flag.clone(),
+        handle: Some(watcher_handle),
+    };
+
+    // Only the take() needs the lock; writing can block on a child that
+    // hasn't started reading stdin yet (e.g. still importing), and holding
+    // the lock across that write would stall the watcher thread's kill().
+    let stdin = child.lock().unwrap().stdin.take();
+    if let Some(mut stdin) = stdin {
         stdin
             .write_all(json_input.as_bytes())
             .map_err(|e| format!("Failed to write to stdin: {}", e))?;
     }
 
-    let stderr_handle = child.stderr.take().map(|stderr| {
+    let stderr = child.lock().unwrap().stderr.take();
+    let stderr_job_id = job_id.clone();
+    let stderr_handle = stderr.map(|stderr| {
         std::thread::spawn(move || {
             let reader = BufReader::new(stderr);
             for line in reader.lines() {
                 if let Ok(text) = line {
-                    log_message("python", &text);
+                    log_event("python", LogLevel::Warn, Some(&stderr_job_id), &text);
                 }
             }
         })
@@ -111,8 +415,10 @@ pub fn execute_python_conversion(
 
     let mut final_result: Option<BackendResult> = None;
     let mut last_stdout = String::new();
+    let mut last_emit: HashMap<usize, Instant> = HashMap::new();
 
-    if let Some(stdout) = child.stdout.take() {
+    let stdout = child.lock().unwrap().stdout.take();
+    if let Some(stdout) = stdout {
         let reader = BufReader::new(stdout);
         for line in reader.lines() {
             if let Ok(mut text) = line {
@@ -125,11 +431,23 @@ pub fn execute_python_conversion(
 
                 match serde_json::from_str::<Value>(&text) {
                     Ok(value) => {
-                        if let Err(err) = app.emit_all("conversion-progress", value.clone()) {
-                            log_message(
-                                "tauri",
-                                &format!("Failed to emit progress event: {}", err),
-                            );
+                        if let Some(progress) =
+                            ProgressEvent::from_backend_value(&job_id, total_files, &value)
+                        {
+                            let now = Instant::now();
+                            let due = last_emit
+                                .get(&progress.file_index)
+                                .map_or(true, |last| now.duration_since(*last) >= PROGRESS_THROTTLE);
+
+                            if due {
+                                last_emit.insert(progress.file_index, now);
+                                if let Err(err) = app.emit_all("conversion-progress", &progress) {
+                                    log_message(
+                                        "tauri",
+                                        &format!("Failed to emit progress event: {}", err),
+                                    );
+                                }
+                            }
                         }
 
                         if let Some(status) = value
@@ -156,6 +474,23 @@ pub fn execute_python_conversion(
                                 message,
                                 outputs,
                             });
+
+                            // Always deliver the terminal event, bypassing the throttle.
+                            let done = ProgressEvent {
+                                job_id: job_id.clone(),
+                                file_index: total_files.saturating_sub(1),
+                                total_files,
+                                current_file: String::new(),
+                                percent: 100.0,
+                                stage: "complete".to_string(),
+                                overall_percent: 100.0,
+                            };
+                            if let Err(err) = app.emit_all("conversion-progress", &done) {
+                                log_message(
+                                    "tauri",
+                                    &format!("Failed to emit completion progress event: {}", err),
+                                );
+                            }
                         }
                     }
                     Err(err) => {
@@ -173,9 +508,29 @@ pub fn execute_python_conversion(
         let _ = handle.join();
     }
 
-    let status = child
-        .wait()
-        .map_err(|e| format!("Failed to wait for Python process: {}", e))?;
+    drop(_watcher_guard);
+
+    let status = {
+        let mut child = child.lock().unwrap();
+        child
+            .wait()
+            .map_err(|e| format!("Failed to wait for Python process: {}", e))?
+    };
+
+    if cancel_flag.load(Ordering::SeqCst) {
+        if emit_lifecycle {
+            if let Err(err) = app.emit_all(
+                "conversion-cancelled",
+                serde_json::json!({ "job_id": job_id }),
+            ) {
+                log_message(
+                    "tauri",
+                    &format!("Failed to emit cancellation event: {}", err),
+                );
+            }
+        }
+        return Err(format!("Conversion {} was cancelled", job_id));
+    }
 
     if !status.success() {
         let code = status.code().unwrap_or(-1);
@@ -187,12 +542,178 @@ pub fn execute_python_conversion(
                 code, last_stdout
             )
         };
+        log_event("tauri", LogLevel::Error, Some(&job_id), &message);
         return Err(message);
     }
 
     final_result.ok_or_else(|| "Python backend did not return a final status".to_string())
 }
 
+/// One shard's outcome from the thread pool below: either it's running and
+/// will be joined, or the OS refused to spawn its thread up front.
+enum ShardTask {
+    Running(std::thread::JoinHandle<Result<BackendResult, String>>),
+    FailedToStart(String),
+}
+
+/// Runs a batch conversion across up to `payload.max_parallel` concurrent
+/// Python backend processes (bounded by the logical CPU count times
+/// [`MAX_WORKER_MULTIPLIER`] regardless of what the caller asks for), sharding `payload.files` and merging their
+/// `conversion-progress` streams and `BackendResult`s into one combined
+/// result.
+///
+/// Emits a single batch job id in the `conversion-started` event, which
+/// `cancel_conversion` can use to cancel every shard at once — see
+/// [`cancel_job`].
+pub fn execute_python_conversion_pooled(
+    app: tauri::AppHandle,
+    payload: ConvertPayload,
+) -> Result<BackendResult, String> {
+    let worker_ceiling = num_cpus::get().saturating_mul(MAX_WORKER_MULTIPLIER).max(1);
+    let worker_count = payload
+        .max_parallel
+        .unwrap_or_else(num_cpus::get)
+        .max(1)
+        .min(payload.files.len().max(1))
+        .min(worker_ceiling);
+
+    if payload.files.len() <= 1 || worker_count <= 1 {
+        return execute_python_conversion(app, payload);
+    }
+
+    let shards = shard_files(&payload.files, worker_count);
+    let shard_job_ids: Vec<String> = shards.iter().map(|_| next_job_id()).collect();
+
+    let batch_id = next_batch_id();
+    register_batch(&batch_id, shard_job_ids.clone());
+    let _batch_guard = BatchRegistryGuard {
+        batch_id: &batch_id,
+    };
+
+    if let Err(err) = app.emit_all(
+        "conversion-started",
+        serde_json::json!({ "job_id": batch_id, "shard_count": shards.len() }),
+    ) {
+        log_message(
+            "tauri",
+            &format!("Failed to emit batch start event: {}", err),
+        );
+    }
+
+    let shard_tasks: Vec<ShardTask> = shards
+        .into_iter()
+        .zip(shard_job_ids)
+        .enumerate()
+        .map(|(shard_index, (files, shard_job_id))| {
+            let shard_app = app.clone();
+            let shard_payload = ConvertPayload {
+                files,
+                format: payload.format.clone(),
+                output: payload.output.clone(),
+                max_parallel: None,
+            };
+
+            let spawned = std::thread::Builder::new()
+                .name(format!("conversion-shard-{}", shard_index))
+                .spawn(move || {
+                    let total_files = shard_payload.files.len();
+                    let json_input = build_convert_request(&shard_payload)?;
+                    run_backend_process(shard_app, total_files, json_input, shard_job_id, false)
+                });
+
+            match spawned {
+                Ok(handle) => ShardTask::Running(handle),
+                Err(err) => ShardTask::FailedToStart(format!(
+                    "Failed to spawn conversion shard {}: {}",
+                    shard_index, err
+                )),
+            }
+        })
+        .collect();
+
+    let shard_results = shard_tasks
+        .into_iter()
+        .map(|task| match task {
+            ShardTask::Running(handle) => handle
+                .join()
+                .unwrap_or_else(|_| Err("Conversion shard thread panicked".to_string())),
+            ShardTask::FailedToStart(err) => Err(err),
+        })
+        .collect::<Vec<_>>();
+
+    // Shard-level conversion-cancelled events are suppressed (emit_lifecycle:
+    // false above), so surface a single batch-level one here if cancellation
+    // is why any shard came back Err, mirroring the single conversion-started
+    // event emitted before fan-out.
+    if shard_results.iter().any(|result| {
+        result
+            .as_ref()
+            .is_err_and(|err| err.ends_with("was cancelled"))
+    }) {
+        if let Err(err) = app.emit_all(
+            "conversion-cancelled",
+            serde_json::json!({ "job_id": batch_id }),
+        ) {
+            log_message(
+                "tauri",
+                &format!("Failed to emit batch cancellation event: {}", err),
+            );
+        }
+    }
+
+    merge_shard_results(shard_results)
+}
+
+/// Splits `files` into up to `worker_count` roughly-equal shards, dropping
+/// any shard that ends up empty (more workers than files).
+fn shard_files(files: &[String], worker_count: usize) -> Vec<Vec<String>> {
+    let mut shards = vec![Vec::new(); worker_count];
+    for (index, file) in files.iter().enumerate() {
+        shards[index % worker_count].push(file.clone());
+    }
+    shards.into_iter().filter(|shard| !shard.is_empty()).collect()
+}
+
+/// Combines each shard's outcome into one `BackendResult`, surfacing partial
+/// successes when some shards fail rather than discarding the shards that
+/// completed.
+fn merge_shard_results(results: Vec<Result<BackendResult, String>>) -> Result<BackendResult, String> {
+    let mut outputs = Vec::new();
+    let mut errors = Vec::new();
+    let mut succeeded = 0usize;
+
+    for result in results {
+        match result {
+            Ok(result) => {
+                succeeded += 1;
+                outputs.extend(result.outputs);
+            }
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(BackendResult {
+            status: "ok".to_string(),
+            message: format!("Converted {} file(s) across {} shard(s)", outputs.len(), succeeded),
+            outputs,
+        })
+    } else if succeeded > 0 {
+        Ok(BackendResult {
+            status: "partial".to_string(),
+            message: format!(
+                "{} of {} shard(s) failed: {}",
+                errors.len(),
+                errors.len() + succeeded,
+                errors.join("; ")
+            ),
+            outputs,
+        })
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
 fn resolve_python(app: &tauri::AppHandle) -> Result<PythonResolution, String> {
     let backend_path = app
         .path_resolver()
@@ -270,3 +791,49 @@ fn derive_python_home(python_bin: &Path) -> Option<PathBuf> {
         Some(parent.to_path_buf())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overall_percent_scales_by_file_index() {
+        assert_eq!(overall_percent(0, 4, 0.0), 0.0);
+        assert_eq!(overall_percent(0, 4, 50.0), 12.5);
+        assert_eq!(overall_percent(2, 4, 0.0), 50.0);
+        assert_eq!(overall_percent(3, 4, 100.0), 100.0);
+    }
+
+    #[test]
+    fn overall_percent_clamps_out_of_range_file_percent() {
+        assert_eq!(overall_percent(0, 2, -10.0), 0.0);
+        assert_eq!(overall_percent(0, 2, 150.0), 50.0);
+    }
+
+    #[test]
+    fn overall_percent_handles_empty_batch() {
+        assert_eq!(overall_percent(0, 0, 50.0), 0.0);
+    }
+
+    fn files(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn shard_files_distributes_round_robin() {
+        let shards = shard_files(&files(&["a", "b", "c", "d", "e"]), 2);
+        assert_eq!(shards, vec![files(&["a", "c", "e"]), files(&["b", "d"])]);
+    }
+
+    #[test]
+    fn shard_files_drops_empty_shards_when_workers_exceed_files() {
+        let shards = shard_files(&files(&["a", "b"]), 5);
+        assert_eq!(shards, vec![files(&["a"]), files(&["b"])]);
+    }
+
+    #[test]
+    fn shard_files_handles_empty_input() {
+        let shards = shard_files(&files(&[]), 4);
+        assert!(shards.is_empty());
+    }
+}
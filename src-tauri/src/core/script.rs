@@ -0,0 +1,112 @@
+//! Embedded Lua scripting for user-defined conversion pipelines.
+//!
+//! A script calls `add_step(name, params)` to append operations (normalize,
+//! trim silence, convert, tag, ...) that are sent to the Python backend as an
+//! ordered `steps` array, instead of the fixed `"operation": "convert"`
+//! request built by [`crate::core::python::execute_python_conversion`].
+
+use crate::core::logging::log_message;
+use mlua::{Lua, LuaSerdeExt, Value as LuaValue, VmState};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Hard cap on pipeline length so a runaway or malicious script can't build
+/// an unbounded backend request.
+const MAX_PIPELINE_STEPS: usize = 64;
+
+/// Hard wall-clock budget for running a pipeline script. `MAX_PIPELINE_STEPS`
+/// only bounds how many steps a script *collects*; a script that never calls
+/// `add_step` (e.g. an infinite loop) would otherwise run forever on the
+/// blocking-task pool that also backs `convert_audio`/`analyze_audio`.
+const MAX_PIPELINE_DURATION: Duration = Duration::from_secs(2);
+
+/// One step of a user-defined conversion pipeline, as produced by `add_step`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PipelineStep {
+    pub name: String,
+    #[serde(default)]
+    pub params: JsonValue,
+}
+
+/// Evaluates `script` in a sandboxed Lua interpreter and returns the ordered
+/// list of pipeline steps it built via `add_step`.
+pub fn build_pipeline(script: &str) -> Result<Vec<PipelineStep>, String> {
+    let lua = Lua::new();
+    sandbox(&lua).map_err(|e| format!("Failed to sandbox Lua interpreter: {}", e))?;
+
+    let deadline = Instant::now() + MAX_PIPELINE_DURATION;
+    lua.set_interrupt(move |_| {
+        if Instant::now() >= deadline {
+            Err(mlua::Error::RuntimeError(format!(
+                "pipeline script exceeded the {}s execution limit",
+                MAX_PIPELINE_DURATION.as_secs()
+            )))
+        } else {
+            Ok(VmState::Continue)
+        }
+    });
+
+    let steps: Rc<RefCell<Vec<PipelineStep>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let collected = steps.clone();
+    let add_step = lua
+        .create_function(move |lua, (name, params): (String, Option<LuaValue>)| {
+            let mut collected = collected.borrow_mut();
+            if collected.len() >= MAX_PIPELINE_STEPS {
+                return Err(mlua::Error::RuntimeError(format!(
+                    "pipeline exceeded the maximum of {} steps",
+                    MAX_PIPELINE_STEPS
+                )));
+            }
+
+            let params: JsonValue = match params {
+                Some(value) => lua.from_value(value)?,
+                None => JsonValue::Null,
+            };
+
+            collected.push(PipelineStep { name, params });
+            Ok(())
+        })
+        .map_err(|e| format!("Failed to register add_step: {}", e))?;
+
+    let log_fn = lua
+        .create_function(|_, message: String| {
+            log_message("lua", &message);
+            Ok(())
+        })
+        .map_err(|e| format!("Failed to register log: {}", e))?;
+
+    let globals = lua.globals();
+    globals
+        .set("add_step", add_step)
+        .map_err(|e| format!("Failed to install add_step: {}", e))?;
+    globals
+        .set("log", log_fn)
+        .map_err(|e| format!("Failed to install log: {}", e))?;
+
+    lua.load(script)
+        .exec()
+        .map_err(|e| format!("Pipeline script failed: {}", e))?;
+
+    drop(globals);
+    Ok(Rc::try_unwrap(steps)
+        .map(|cell| cell.into_inner())
+        .unwrap_or_else(|rc| rc.borrow().clone()))
+}
+
+/// Strips the globals a pipeline script shouldn't be able to reach, so a
+/// script can compose conversion steps but can't touch the filesystem,
+/// spawn processes, or load native code. `package` is included alongside
+/// `io`/`os`: left in place, `package.loadlib` can dlopen an arbitrary shared
+/// library and call an arbitrary exported symbol, which is strictly worse
+/// than the filesystem/process access this function blocks.
+fn sandbox(lua: &Lua) -> mlua::Result<()> {
+    let globals = lua.globals();
+    for name in ["io", "os", "package", "require", "dofile", "loadfile", "load"] {
+        globals.set(name, LuaValue::Nil)?;
+    }
+    Ok(())
+}
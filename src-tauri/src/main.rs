@@ -3,15 +3,25 @@
 mod commands;
 mod core;
 
+use crate::core::logging::init_logging;
+
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_notification::init())
+        .setup(|app| {
+            init_logging(&app.handle());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             commands::ping,
-            commands::convert_audio
+            commands::convert_audio,
+            commands::analyze_audio,
+            commands::cancel_conversion,
+            commands::run_pipeline,
+            commands::get_log_path
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");